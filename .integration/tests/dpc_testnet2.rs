@@ -69,7 +69,7 @@ fn dpc_testnet2_integration_test() {
         let view_key = ViewKey::from_private_key(recipient.private_key()).unwrap();
         let decrypted_record = encrypted_record.decrypt(&view_key).unwrap();
         assert_eq!(decrypted_record.owner(), recipient.address());
-        assert_eq!(decrypted_record.value() as i64, Block::<Testnet2>::block_reward(1).0);
+        assert_eq!(decrypted_record.value().as_microcredits(), Block::<Testnet2>::block_reward(1).as_microcredits());
     }
     let transactions = Transactions::from(&[coinbase_transaction]).unwrap();
     let transactions_root = transactions.to_transactions_root().unwrap();
@@ -91,11 +91,11 @@ fn dpc_testnet2_integration_test() {
     let commitments_root = commitments.root();
 
     let timestamp = Utc::now().timestamp();
-    let difficulty_target = Blocks::<Testnet2>::compute_difficulty_target(
-        previous_block.timestamp(),
-        previous_block.difficulty_target(),
-        timestamp,
-    );
+    // `compute_difficulty_target` now averages over a window of recent headers rather than a
+    // single previous block; this close to genesis there isn't yet enough history to fill that
+    // window, so it falls back to the network's `POW_LIMIT`, same as it would for any chain in
+    // its first `DIFFICULTY_WINDOW_SIZE + MEDIAN_TIME_PAST_WINDOW_SIZE` blocks.
+    let difficulty_target = Blocks::<Testnet2>::compute_difficulty_target(&[previous_block.header().clone()]);
 
     // Construct the new block header.
     let header = BlockHeader::new(
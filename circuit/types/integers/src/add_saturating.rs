@@ -0,0 +1,172 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> AddSaturating<Self> for Integer<E, I> {
+    type Output = Self;
+
+    #[inline]
+    fn add_saturating(&self, other: &Integer<E, I>) -> Self::Output {
+        // Determine the variable mode.
+        if self.is_constant() && other.is_constant() {
+            // Compute the sum and return the new constant.
+            witness!(|self, other| console::Integer::new(self.saturating_add(&other)))
+        } else {
+            // Reuse the field-element summation and carry/sign analysis from `add_flagged` to get
+            // the wrapped sum along with a flag for whether it overflowed (or, for signed
+            // integers, underflowed).
+            let (sum, is_flagged) = self.add_flagged(other);
+
+            match I::is_signed() {
+                // For signed addition, an overflow means the (equal-signed) operands were both
+                // positive, and an underflow means they were both negative; `self`'s sign alone
+                // distinguishes which one occurred, since overflow/underflow requires `self` and
+                // `other` to share a sign.
+                true => {
+                    let clamp = Integer::ternary(self.msb(), &Integer::constant(console::Integer::MIN), &Integer::constant(console::Integer::MAX));
+                    Integer::ternary(&is_flagged, &clamp, &sum)
+                }
+                // For unsigned addition, an overflow can only saturate to the maximum value.
+                false => Integer::ternary(&is_flagged, &Integer::constant(console::Integer::MAX), &sum),
+            }
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn AddSaturating<Integer<E, I>, Output = Integer<E, I>>>
+    for Integer<E, I>
+{
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        // The cost is the cost of `add_flagged` (to get the sum and the overflow flag), plus an
+        // `I::BITS`-wide `ternary` to select the clamped value (and, for signed integers, a second
+        // `ternary` to pick between `MAX` and `MIN`).
+        match I::is_signed() {
+            true => match (case.0, case.1) {
+                (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
+                (Mode::Constant, _) => Count::is(0, 0, 2 * I::BITS + 2, 2 * I::BITS + 5),
+                (_, Mode::Constant) => Count::is(0, 0, 2 * I::BITS + 3, 2 * I::BITS + 6),
+                (_, _) => Count::is(0, 0, 2 * I::BITS + 4, 2 * I::BITS + 7),
+            },
+            false => match (case.0, case.1) {
+                (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
+                (_, _) => Count::is(0, 0, I::BITS + 2, I::BITS + 4),
+            },
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn AddSaturating<Integer<E, I>, Output = Integer<E, I>>>
+    for Integer<E, I>
+{
+    type Case = (Mode, Mode);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match (case.0, case.1) {
+            (Mode::Constant, Mode::Constant) => Mode::Constant,
+            (_, _) => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use core::ops::RangeInclusive;
+
+    const ITERATIONS: u64 = 128;
+
+    fn check_add<I: IntegerType>(
+        name: &str,
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        second: console::Integer<<Circuit as Environment>::Network, I>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::new(mode_b, second);
+        let expected = first.saturating_add(&second);
+        println!("{}: {:?} + {:?} = {:?}", name, first, second, expected);
+        Circuit::scope(name, || {
+            let candidate = a.add_saturating(&b);
+            println!("{}: {:?} + {:?} = {:?}", name, a, b, candidate);
+            assert_eq!(expected, *candidate.eject_value());
+            assert_eq!(console::Integer::new(expected), candidate.eject_value());
+            assert_count!(AddSaturating(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b));
+            assert_output_mode!(AddSaturating(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b), candidate);
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+
+            let name = format!("Add: {} + {} {}", mode_a, mode_b, i);
+            check_add::<I>(&name, first, second, mode_a, mode_b);
+            check_add::<I>(&name, second, first, mode_a, mode_b); // Commute the operation.
+        }
+
+        // Clamp at MAX.
+        check_add::<I>("MAX + 1", console::Integer::MAX, console::Integer::one(), mode_a, mode_b);
+        check_add::<I>("1 + MAX", console::Integer::one(), console::Integer::MAX, mode_a, mode_b);
+        check_add::<I>("MAX + MAX", console::Integer::MAX, console::Integer::MAX, mode_a, mode_b);
+
+        // Clamp at MIN.
+        if I::is_signed() {
+            check_add::<I>("MIN + (-1)", console::Integer::MIN, -console::Integer::one(), mode_a, mode_b);
+            check_add::<I>("-1 + MIN", -console::Integer::one(), console::Integer::MIN, mode_a, mode_b);
+            check_add::<I>("MIN + MIN", console::Integer::MIN, console::Integer::MIN, mode_a, mode_b);
+        }
+    }
+
+    fn run_exhaustive_test<I: IntegerType>(mode_a: Mode, mode_b: Mode)
+    where
+        RangeInclusive<I>: Iterator<Item = I>,
+    {
+        for first in I::MIN..=I::MAX {
+            for second in I::MIN..=I::MAX {
+                let first = console::Integer::<_, I>::new(first);
+                let second = console::Integer::<_, I>::new(second);
+
+                let name = format!("Add: ({} + {})", first, second);
+                check_add::<I>(&name, first, second, mode_a, mode_b);
+            }
+        }
+    }
+
+    test_integer_binary!(run_test, i8, plus);
+    test_integer_binary!(run_test, i16, plus);
+    test_integer_binary!(run_test, i32, plus);
+    test_integer_binary!(run_test, i64, plus);
+    test_integer_binary!(run_test, i128, plus);
+
+    test_integer_binary!(run_test, u8, plus);
+    test_integer_binary!(run_test, u16, plus);
+    test_integer_binary!(run_test, u32, plus);
+    test_integer_binary!(run_test, u64, plus);
+    test_integer_binary!(run_test, u128, plus);
+
+    test_integer_binary!(#[ignore], run_exhaustive_test, u8, plus, exhaustive);
+    test_integer_binary!(#[ignore], run_exhaustive_test, i8, plus, exhaustive);
+}
@@ -0,0 +1,86 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    account::{bech32m, private_key::PrivateKey, view_key, view_key::ADDRESS_DOMAIN},
+    network::Network,
+};
+
+use std::{fmt, marker::PhantomData, str::FromStr};
+
+/// An error that can occur while parsing an [`Address`] from its Bech32m string encoding.
+#[derive(Debug, PartialEq, Eq)]
+pub enum AddressError {
+    /// The Bech32m string was malformed or had an unexpected prefix.
+    Bech32m(bech32m::Bech32mError),
+    /// The decoded payload was not exactly 32 bytes.
+    InvalidLength(usize),
+}
+
+impl fmt::Display for AddressError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bech32m(error) => write!(f, "{error}"),
+            Self::InvalidLength(len) => write!(f, "address payload must be 32 bytes, found {len}"),
+        }
+    }
+}
+
+impl std::error::Error for AddressError {}
+
+/// An account's public address, to which records and transfers are addressed.
+///
+/// `Address`'s string encoding is a Bech32m string with `N::ADDRESS_HRP` as its human-readable
+/// prefix (e.g. `aleo1...`).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Address<N: Network> {
+    bytes: [u8; 32],
+    _network: PhantomData<N>,
+}
+
+impl<N: Network> Address<N> {
+    /// Derives the address for the given private key.
+    pub fn from_private_key(private_key: &PrivateKey<N>) -> Self {
+        Self { bytes: view_key::derive(ADDRESS_DOMAIN, &private_key.to_bytes()), _network: PhantomData }
+    }
+
+    /// Returns the address's underlying bytes.
+    pub const fn to_bytes(&self) -> [u8; 32] {
+        self.bytes
+    }
+
+    /// Reconstructs an address directly from its underlying bytes, e.g. when deserializing a
+    /// [`Record`](crate::record::Record).
+    pub(crate) const fn from_bytes(bytes: [u8; 32]) -> Self {
+        Self { bytes, _network: PhantomData }
+    }
+}
+
+impl<N: Network> fmt::Display for Address<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", bech32m::encode(N::ADDRESS_HRP, &self.bytes))
+    }
+}
+
+impl<N: Network> FromStr for Address<N> {
+    type Err = AddressError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let payload = bech32m::decode(N::ADDRESS_HRP, s).map_err(AddressError::Bech32m)?;
+        let bytes: [u8; 32] = payload.clone().try_into().map_err(|_| AddressError::InvalidLength(payload.len()))?;
+        Ok(Self { bytes, _network: PhantomData })
+    }
+}
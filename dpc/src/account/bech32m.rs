@@ -0,0 +1,275 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! A Bech32m codec for human-readable, checksummed encodings of `Address`, `ViewKey`, and
+//! `PrivateKey` payloads (e.g. `aleo1...`, `aleotest1...`), following the same construction as
+//! segwit v1+ and Zcash unified addresses.
+//!
+//! This implements [BIP-350](https://github.com/bitcoin/bips/blob/master/bip-0350.mediawiki)'s
+//! Bech32m variant, which differs from the original Bech32 checksum only in the constant that is
+//! XOR-ed into the polymod before appending the checksum.
+
+use std::fmt;
+
+/// The Bech32m checksum constant, XOR-ed into the polymod instead of Bech32's `1`.
+const BECH32M_CONST: u32 = 0x2bc830a3;
+
+/// The separator between the human-readable prefix and the data part.
+const SEPARATOR: char = '1';
+
+/// The Bech32 character set, used to map 5-bit groups to characters and back.
+const CHARSET: &[u8] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+/// Errors that can occur while encoding or decoding a Bech32m string.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Bech32mError {
+    /// The string mixed uppercase and lowercase characters.
+    MixedCase,
+    /// The string did not contain the `1` separator.
+    MissingSeparator,
+    /// The human-readable prefix was empty, or didn't match the expected network prefix.
+    InvalidHrp,
+    /// The data part contained a character outside the Bech32 charset.
+    InvalidCharacter(char),
+    /// The data part was too short to contain a checksum.
+    TooShort,
+    /// The checksum did not verify.
+    InvalidChecksum,
+    /// The decoded 5-bit groups did not convert cleanly back to bytes.
+    InvalidPadding,
+}
+
+impl fmt::Display for Bech32mError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MixedCase => write!(f, "bech32m string mixes uppercase and lowercase characters"),
+            Self::MissingSeparator => write!(f, "bech32m string is missing the '1' separator"),
+            Self::InvalidHrp => write!(f, "bech32m string has an unexpected human-readable prefix"),
+            Self::InvalidCharacter(c) => write!(f, "bech32m string contains an invalid character '{c}'"),
+            Self::TooShort => write!(f, "bech32m string is too short to contain a checksum"),
+            Self::InvalidChecksum => write!(f, "bech32m checksum does not match"),
+            Self::InvalidPadding => write!(f, "bech32m data does not convert cleanly to bytes"),
+        }
+    }
+}
+
+impl std::error::Error for Bech32mError {}
+
+/// Encodes `data` (an arbitrary-length byte payload, e.g. a 32-byte key or address) as a Bech32m
+/// string with the given human-readable prefix `hrp`.
+pub fn encode(hrp: &str, data: &[u8]) -> String {
+    let values = convert_bits(data, 8, 5, true);
+    let checksum = create_checksum(hrp, &values);
+
+    let mut output = String::with_capacity(hrp.len() + 1 + values.len() + checksum.len());
+    output.push_str(hrp);
+    output.push(SEPARATOR);
+    for &value in values.iter().chain(checksum.iter()) {
+        output.push(CHARSET[value as usize] as char);
+    }
+    output
+}
+
+/// Decodes a Bech32m string, verifying its checksum and that its human-readable prefix matches
+/// `expected_hrp`. Returns the decoded byte payload (with the 6-character checksum removed).
+pub fn decode(expected_hrp: &str, input: &str) -> Result<Vec<u8>, Bech32mError> {
+    if input.chars().any(|c| c.is_ascii_uppercase()) && input.chars().any(|c| c.is_ascii_lowercase()) {
+        return Err(Bech32mError::MixedCase);
+    }
+    let lowercase = input.to_ascii_lowercase();
+
+    let separator_index = lowercase.rfind(SEPARATOR).ok_or(Bech32mError::MissingSeparator)?;
+    let (hrp, rest) = lowercase.split_at(separator_index);
+    let data_part = &rest[1..];
+
+    if hrp.is_empty() || hrp != expected_hrp {
+        return Err(Bech32mError::InvalidHrp);
+    }
+    if data_part.len() < 6 {
+        return Err(Bech32mError::TooShort);
+    }
+
+    let mut values = Vec::with_capacity(data_part.len());
+    for c in data_part.chars() {
+        let value = CHARSET.iter().position(|&b| b as char == c).ok_or(Bech32mError::InvalidCharacter(c))?;
+        values.push(value as u8);
+    }
+
+    if !verify_checksum(hrp, &values) {
+        return Err(Bech32mError::InvalidChecksum);
+    }
+
+    let payload = &values[..values.len() - 6];
+    convert_bits_checked(payload, 5, 8, false).ok_or(Bech32mError::InvalidPadding)
+}
+
+/// Converts a sequence of `from_bits`-wide groups into a sequence of `to_bits`-wide groups,
+/// padding the final group with zero bits if `pad` is true.
+fn convert_bits(data: &[u8], from_bits: u32, to_bits: u32, pad: bool) -> Vec<u8> {
+    let mut acc: u32 = 0;
+    let mut bits: u32 = 0;
+    let maxv = (1u32 << to_bits) - 1;
+    let mut result = Vec::new();
+
+    for &value in data {
+        acc = (acc << from_bits) | value as u32;
+        bits += from_bits;
+        while bits >= to_bits {
+            bits -= to_bits;
+            result.push(((acc >> bits) & maxv) as u8);
+        }
+    }
+
+    if pad && bits > 0 {
+        result.push(((acc << (to_bits - bits)) & maxv) as u8);
+    }
+
+    result
+}
+
+/// Like [`convert_bits`], but rejects non-zero padding bits, as required when decoding (a
+/// correctly-encoded string never sets them).
+fn convert_bits_checked(data: &[u8], from_bits: u32, to_bits: u32, pad: bool) -> Option<Vec<u8>> {
+    let mut acc: u32 = 0;
+    let mut bits: u32 = 0;
+    let maxv = (1u32 << to_bits) - 1;
+    let max_acc = (1u32 << (from_bits + to_bits - 1)) - 1;
+    let mut result = Vec::new();
+
+    for &value in data {
+        if (value as u32) >> from_bits != 0 {
+            return None;
+        }
+        acc = ((acc << from_bits) | value as u32) & max_acc;
+        bits += from_bits;
+        while bits >= to_bits {
+            bits -= to_bits;
+            result.push(((acc >> bits) & maxv) as u8);
+        }
+    }
+
+    if pad {
+        if bits > 0 {
+            result.push(((acc << (to_bits - bits)) & maxv) as u8);
+        }
+    } else if bits >= from_bits || ((acc << (to_bits - bits)) & maxv) != 0 {
+        return None;
+    }
+
+    Some(result)
+}
+
+/// Expands the human-readable prefix into the form used by the checksum polymod: the high bits
+/// of each character, then a zero separator, then the low bits of each character.
+fn hrp_expand(hrp: &str) -> Vec<u8> {
+    let mut result = Vec::with_capacity(hrp.len() * 2 + 1);
+    result.extend(hrp.bytes().map(|b| b >> 5));
+    result.push(0);
+    result.extend(hrp.bytes().map(|b| b & 31));
+    result
+}
+
+/// The Bech32 polymod function, used to both generate and verify checksums.
+fn polymod(values: &[u8]) -> u32 {
+    const GENERATORS: [u32; 5] = [0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3];
+    let mut chk: u32 = 1;
+    for &value in values {
+        let top = chk >> 25;
+        chk = ((chk & 0x1ffffff) << 5) ^ value as u32;
+        for (i, &generator) in GENERATORS.iter().enumerate() {
+            if (top >> i) & 1 == 1 {
+                chk ^= generator;
+            }
+        }
+    }
+    chk
+}
+
+/// Computes the 6-character Bech32m checksum for the given human-readable prefix and data values.
+fn create_checksum(hrp: &str, data: &[u8]) -> Vec<u8> {
+    let mut values = hrp_expand(hrp);
+    values.extend_from_slice(data);
+    values.extend_from_slice(&[0u8; 6]);
+
+    let polymod = polymod(&values) ^ BECH32M_CONST;
+    (0..6).map(|i| ((polymod >> (5 * (5 - i))) & 31) as u8).collect()
+}
+
+/// Verifies that `data` (the payload followed by its 6-character checksum) has a valid Bech32m
+/// checksum under the given human-readable prefix.
+fn verify_checksum(hrp: &str, data: &[u8]) -> bool {
+    let mut values = hrp_expand(hrp);
+    values.extend_from_slice(data);
+    polymod(&values) == BECH32M_CONST
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HRP: &str = "aleo";
+
+    #[test]
+    fn test_encode_decode_roundtrip() {
+        let payload = [7u8; 32];
+        let encoded = encode(HRP, &payload);
+        assert!(encoded.starts_with("aleo1"));
+        let decoded = decode(HRP, &encoded).unwrap();
+        assert_eq!(decoded, payload);
+    }
+
+    #[test]
+    fn test_decode_rejects_mixed_case() {
+        let payload = [7u8; 32];
+        let mut encoded = encode(HRP, &payload);
+        // Uppercase a single character in the data part to create a mixed-case string.
+        let idx = encoded.len() - 1;
+        encoded.replace_range(idx..=idx, &encoded[idx..=idx].to_ascii_uppercase());
+        assert_eq!(decode(HRP, &encoded), Err(Bech32mError::MixedCase));
+    }
+
+    #[test]
+    fn test_decode_rejects_corrupted_checksum() {
+        let payload = [7u8; 32];
+        let mut encoded = encode(HRP, &payload);
+        // Flip the final checksum character to a different valid charset character.
+        let last = encoded.pop().unwrap();
+        let replacement = CHARSET.iter().map(|&b| b as char).find(|&c| c != last).unwrap();
+        encoded.push(replacement);
+        assert_eq!(decode(HRP, &encoded), Err(Bech32mError::InvalidChecksum));
+    }
+
+    #[test]
+    fn test_decode_rejects_wrong_hrp() {
+        let payload = [7u8; 32];
+        let encoded = encode(HRP, &payload);
+        assert_eq!(decode("aleotest", &encoded), Err(Bech32mError::InvalidHrp));
+    }
+
+    #[test]
+    fn test_decode_rejects_missing_separator() {
+        assert_eq!(decode(HRP, "aleoqpzry9x8gf2tvdw0s3jn54khce6mua7l"), Err(Bech32mError::MissingSeparator));
+    }
+
+    #[test]
+    fn test_roundtrip_various_lengths() {
+        for len in [0usize, 1, 16, 32, 64] {
+            let payload: Vec<u8> = (0..len as u8).collect();
+            let encoded = encode(HRP, &payload);
+            assert_eq!(decode(HRP, &encoded).unwrap(), payload);
+        }
+    }
+}
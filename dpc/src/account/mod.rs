@@ -0,0 +1,94 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+pub mod address;
+pub use address::{Address, AddressError};
+
+pub mod bech32m;
+
+pub mod private_key;
+pub use private_key::{PrivateKey, PrivateKeyError};
+
+pub mod view_key;
+pub use view_key::{ViewKey, ViewKeyError};
+
+use crate::network::Network;
+use rand::{CryptoRng, Rng};
+
+/// An account: a private key together with the view key and address derived from it.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Account<N: Network> {
+    private_key: PrivateKey<N>,
+    view_key: ViewKey<N>,
+    address: Address<N>,
+}
+
+impl<N: Network> Account<N> {
+    /// Samples a new account uniformly at random.
+    pub fn new<R: Rng + CryptoRng>(rng: &mut R) -> Self {
+        let private_key = PrivateKey::new(rng);
+        let view_key = ViewKey::from_private_key(&private_key);
+        let address = Address::from_private_key(&private_key);
+        Self { private_key, view_key, address }
+    }
+
+    /// Returns this account's private key.
+    pub const fn private_key(&self) -> &PrivateKey<N> {
+        &self.private_key
+    }
+
+    /// Returns this account's view key.
+    pub const fn view_key(&self) -> &ViewKey<N> {
+        &self.view_key
+    }
+
+    /// Returns this account's address.
+    pub const fn address(&self) -> Address<N> {
+        self.address
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network::Testnet2;
+    use rand::thread_rng;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_view_key_and_address_are_consistent_with_private_key() {
+        let account = Account::<Testnet2>::new(&mut thread_rng());
+        assert_eq!(ViewKey::from_private_key(account.private_key()), *account.view_key());
+        assert_eq!(Address::from_private_key(account.private_key()), account.address());
+    }
+
+    #[test]
+    fn test_display_from_str_roundtrip() {
+        let account = Account::<Testnet2>::new(&mut thread_rng());
+
+        let address_string = account.address().to_string();
+        assert!(address_string.starts_with(Testnet2::ADDRESS_HRP));
+        assert_eq!(Address::<Testnet2>::from_str(&address_string).unwrap(), account.address());
+
+        let view_key_string = account.view_key().to_string();
+        assert!(view_key_string.starts_with(Testnet2::VIEW_KEY_HRP));
+        assert_eq!(ViewKey::<Testnet2>::from_str(&view_key_string).unwrap(), *account.view_key());
+
+        let private_key_string = account.private_key().to_string();
+        assert!(private_key_string.starts_with(Testnet2::PRIVATE_KEY_HRP));
+        assert_eq!(PrivateKey::<Testnet2>::from_str(&private_key_string).unwrap(), *account.private_key());
+    }
+}
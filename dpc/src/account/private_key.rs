@@ -0,0 +1,83 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{account::bech32m, network::Network};
+
+use rand::{CryptoRng, Rng};
+use std::{fmt, marker::PhantomData, str::FromStr};
+
+/// An error that can occur while parsing a [`PrivateKey`] from its Bech32m string encoding.
+#[derive(Debug, PartialEq, Eq)]
+pub enum PrivateKeyError {
+    /// The Bech32m string was malformed or had an unexpected prefix.
+    Bech32m(bech32m::Bech32mError),
+    /// The decoded payload was not exactly 32 bytes.
+    InvalidLength(usize),
+}
+
+impl fmt::Display for PrivateKeyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bech32m(error) => write!(f, "{error}"),
+            Self::InvalidLength(len) => write!(f, "private key payload must be 32 bytes, found {len}"),
+        }
+    }
+}
+
+impl std::error::Error for PrivateKeyError {}
+
+/// An account's private key: 32 bytes of seed material from which its [`ViewKey`](super::ViewKey)
+/// and [`Address`](super::Address) are derived.
+///
+/// `PrivateKey`'s string encoding is a Bech32m string with `N::PRIVATE_KEY_HRP` as its
+/// human-readable prefix (e.g. `aprivatekey1...`), matching the encoding used for
+/// [`Address`](super::Address) and [`ViewKey`](super::ViewKey).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PrivateKey<N: Network> {
+    seed: [u8; 32],
+    _network: PhantomData<N>,
+}
+
+impl<N: Network> PrivateKey<N> {
+    /// Samples a new private key uniformly at random.
+    pub fn new<R: Rng + CryptoRng>(rng: &mut R) -> Self {
+        let mut seed = [0u8; 32];
+        rng.fill(&mut seed);
+        Self { seed, _network: PhantomData }
+    }
+
+    /// Returns the private key's underlying seed bytes.
+    pub const fn to_bytes(&self) -> [u8; 32] {
+        self.seed
+    }
+}
+
+impl<N: Network> fmt::Display for PrivateKey<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", bech32m::encode(N::PRIVATE_KEY_HRP, &self.seed))
+    }
+}
+
+impl<N: Network> FromStr for PrivateKey<N> {
+    type Err = PrivateKeyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let payload = bech32m::decode(N::PRIVATE_KEY_HRP, s).map_err(PrivateKeyError::Bech32m)?;
+        let seed: [u8; 32] =
+            payload.clone().try_into().map_err(|_| PrivateKeyError::InvalidLength(payload.len()))?;
+        Ok(Self { seed, _network: PhantomData })
+    }
+}
@@ -0,0 +1,120 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    account::{bech32m, private_key::PrivateKey},
+    network::Network,
+};
+
+use blake2::{
+    digest::{Update, VariableOutput},
+    Blake2bVar,
+};
+use std::{fmt, marker::PhantomData, str::FromStr};
+
+/// An error that can occur while parsing a [`ViewKey`] from its Bech32m string encoding.
+#[derive(Debug, PartialEq, Eq)]
+pub enum ViewKeyError {
+    /// The Bech32m string was malformed or had an unexpected prefix.
+    Bech32m(bech32m::Bech32mError),
+    /// The decoded payload was not exactly 32 bytes.
+    InvalidLength(usize),
+}
+
+impl fmt::Display for ViewKeyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bech32m(error) => write!(f, "{error}"),
+            Self::InvalidLength(len) => write!(f, "view key payload must be 32 bytes, found {len}"),
+        }
+    }
+}
+
+impl std::error::Error for ViewKeyError {}
+
+/// An account's view key: the key capable of decrypting records addressed to the corresponding
+/// [`Address`](super::Address), without being able to spend them.
+///
+/// `ViewKey`'s string encoding is a Bech32m string with `N::VIEW_KEY_HRP` as its human-readable
+/// prefix (e.g. `aviewkey1...`).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ViewKey<N: Network> {
+    bytes: [u8; 32],
+    _network: PhantomData<N>,
+}
+
+impl<N: Network> ViewKey<N> {
+    /// Derives the view key for the given private key.
+    ///
+    /// The view key is derived from the address (rather than directly from the private key), so
+    /// that it lands on the same value [`record_key`] computes from the address alone when
+    /// encrypting a record — this is what lets [`ViewKey`] decrypt records addressed to its
+    /// account's [`Address`](super::Address).
+    pub fn from_private_key(private_key: &PrivateKey<N>) -> Self {
+        let address_bytes = derive(ADDRESS_DOMAIN, &private_key.to_bytes());
+        Self { bytes: record_key(&address_bytes), _network: PhantomData }
+    }
+
+    /// Returns the view key's underlying bytes.
+    pub const fn to_bytes(&self) -> [u8; 32] {
+        self.bytes
+    }
+}
+
+impl<N: Network> fmt::Display for ViewKey<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", bech32m::encode(N::VIEW_KEY_HRP, &self.bytes))
+    }
+}
+
+impl<N: Network> FromStr for ViewKey<N> {
+    type Err = ViewKeyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let payload = bech32m::decode(N::VIEW_KEY_HRP, s).map_err(ViewKeyError::Bech32m)?;
+        let bytes: [u8; 32] = payload.clone().try_into().map_err(|_| ViewKeyError::InvalidLength(payload.len()))?;
+        Ok(Self { bytes, _network: PhantomData })
+    }
+}
+
+/// The domain tag used to derive an [`Address`](super::Address) from a private key; shared with
+/// [`ViewKey::from_private_key`] so the two derivations start from the same address bytes.
+pub(crate) const ADDRESS_DOMAIN: &[u8] = b"AleoAddress";
+
+/// The domain tag used to derive a record's symmetric encryption key from the recipient's address.
+///
+/// Note: this is a simplified stand-in for Aleo's real Diffie-Hellman-based record encryption,
+/// which this sparse tree has no elliptic-curve arithmetic to implement. Because [`record_key`]
+/// is a pure function of the (public) address, this scheme does not actually provide
+/// confidentiality against a party who only knows the address — unlike the real scheme, where
+/// only the private key's holder can derive the key the address was encrypted under.
+pub(crate) const RECORD_KEY_DOMAIN: &[u8] = b"AleoRecordKey";
+
+/// Derives the symmetric key used to encrypt/decrypt a record addressed to `address_bytes`.
+pub(crate) fn record_key(address_bytes: &[u8; 32]) -> [u8; 32] {
+    derive(RECORD_KEY_DOMAIN, address_bytes)
+}
+
+/// Domain-separated BLAKE2b key derivation, shared by [`ViewKey::from_private_key`] and
+/// [`super::Address::from_private_key`] to derive distinct child keys from the same private key.
+pub(super) fn derive(domain: &[u8], seed: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = Blake2bVar::new(32).expect("32 is a valid BLAKE2b digest length");
+    hasher.update(domain);
+    hasher.update(seed);
+    let mut output = [0u8; 32];
+    hasher.finalize_variable(&mut output).expect("BLAKE2b finalization should not fail");
+    output
+}
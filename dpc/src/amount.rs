@@ -0,0 +1,299 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! A typed `Amount`, denominated in microcredits, with checked/saturating arithmetic and a
+//! denomination-aware formatter/parser, so that block rewards and record values no longer
+//! travel as bare integers.
+
+use std::{
+    fmt,
+    str::FromStr,
+};
+
+/// The maximum number of credits that will ever be in circulation.
+pub const MAX_CREDITS_SUPPLY: i64 = 1_000_000_000;
+
+/// The number of microcredits in one whole credit.
+pub const MICROCREDITS_PER_CREDIT: i64 = 1_000_000;
+
+/// The maximum number of microcredits that will ever be in circulation.
+pub const MAX_MICROCREDITS_SUPPLY: i64 = MAX_CREDITS_SUPPLY * MICROCREDITS_PER_CREDIT;
+
+/// The denomination an [`Amount`] is formatted in or parsed from.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Denomination {
+    /// The base unit: microcredits.
+    MicroCredits,
+    /// The whole unit: credits (1 credit = 1,000,000 microcredits).
+    Credits,
+}
+
+impl Denomination {
+    /// Returns the number of decimal places needed to represent one unit of this denomination in
+    /// microcredits.
+    fn decimal_places(&self) -> u32 {
+        match self {
+            Self::MicroCredits => 0,
+            Self::Credits => 6,
+        }
+    }
+}
+
+/// An error that can occur while constructing, parsing, or formatting an [`Amount`].
+#[derive(Debug, PartialEq, Eq)]
+pub enum AmountError {
+    /// The value overflowed past [`MAX_MICROCREDITS_SUPPLY`] (or underflowed past its negation).
+    AmountOverflow,
+    /// The input string was not a valid decimal amount.
+    InvalidString(String),
+    /// The input string specified more fractional digits than the denomination allows.
+    TooPrecise(String),
+}
+
+impl fmt::Display for AmountError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AmountOverflow => write!(f, "amount is out of range of the maximum credits supply"),
+            Self::InvalidString(s) => write!(f, "'{s}' is not a valid amount"),
+            Self::TooPrecise(s) => write!(f, "'{s}' has more fractional digits than the denomination allows"),
+        }
+    }
+}
+
+impl std::error::Error for AmountError {}
+
+/// An amount of credits, denominated in microcredits (the base unit).
+///
+/// `Amount` is a thin newtype over `i64`; it exists so that block rewards, record values, and
+/// other user-facing quantities carry their unit and cannot be silently mixed with an unrelated
+/// integer.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Amount(pub i64);
+
+impl Amount {
+    /// The zero amount.
+    pub const ZERO: Self = Self(0);
+    /// The maximum representable amount, in microcredits.
+    pub const MAX: Self = Self(MAX_MICROCREDITS_SUPPLY);
+    /// The minimum representable amount, in microcredits.
+    pub const MIN: Self = Self(-MAX_MICROCREDITS_SUPPLY);
+
+    /// Creates a new `Amount` from a number of microcredits.
+    pub const fn from_microcredits(microcredits: i64) -> Self {
+        Self(microcredits)
+    }
+
+    /// Creates a new `Amount` from a number of whole credits.
+    pub fn from_credits(credits: i64) -> Self {
+        Self(credits * MICROCREDITS_PER_CREDIT)
+    }
+
+    /// Returns the amount as a number of microcredits.
+    pub const fn as_microcredits(&self) -> i64 {
+        self.0
+    }
+
+    /// Adds two amounts, returning `None` if the result overflows [`Amount::MAX`] or underflows
+    /// [`Amount::MIN`].
+    pub fn checked_add(&self, other: Self) -> Option<Self> {
+        self.0.checked_add(other.0).filter(|sum| (Self::MIN.0..=Self::MAX.0).contains(sum)).map(Self)
+    }
+
+    /// Subtracts two amounts, returning `None` if the result overflows [`Amount::MAX`] or
+    /// underflows [`Amount::MIN`].
+    pub fn checked_sub(&self, other: Self) -> Option<Self> {
+        self.0.checked_sub(other.0).filter(|difference| (Self::MIN.0..=Self::MAX.0).contains(difference)).map(Self)
+    }
+
+    /// Adds two amounts, clamping the result to [`Amount::MAX`] or [`Amount::MIN`] on overflow.
+    pub fn saturating_add(&self, other: Self) -> Self {
+        Self(self.0.saturating_add(other.0).clamp(Self::MIN.0, Self::MAX.0))
+    }
+
+    /// Subtracts two amounts, clamping the result to [`Amount::MAX`] or [`Amount::MIN`] on
+    /// overflow.
+    pub fn saturating_sub(&self, other: Self) -> Self {
+        Self(self.0.saturating_sub(other.0).clamp(Self::MIN.0, Self::MAX.0))
+    }
+
+    /// Formats this amount in the given `denomination`.
+    ///
+    /// The integer part is emitted first. If the fractional part is non-zero, it follows after a
+    /// `.`, zero-padded on the left to `denomination`'s number of decimal places and with trailing
+    /// zeros trimmed (but never dropping significant digits); otherwise no `.` is emitted.
+    /// Negative amounts are prefixed with a `-` sign.
+    pub fn to_string_in(&self, denomination: Denomination) -> String {
+        let decimal_places = denomination.decimal_places();
+        let sign = if self.0 < 0 { "-" } else { "" };
+        let magnitude = self.0.unsigned_abs();
+
+        if decimal_places == 0 {
+            return format!("{sign}{magnitude}");
+        }
+
+        let scale = 10u64.pow(decimal_places);
+        let integer_part = magnitude / scale;
+        let fractional_part = magnitude % scale;
+
+        if fractional_part == 0 {
+            return format!("{sign}{integer_part}");
+        }
+
+        let mut fraction_str = format!("{:0width$}", fractional_part, width = decimal_places as usize);
+        while fraction_str.ends_with('0') {
+            fraction_str.pop();
+        }
+        format!("{sign}{integer_part}.{fraction_str}")
+    }
+
+    /// Parses an amount from a decimal string in the given `denomination`.
+    ///
+    /// Accepts an optional leading `-`, an optional decimal point, and rejects a fractional part
+    /// with more digits than the denomination supports. Returns [`AmountError::AmountOverflow`] if
+    /// the parsed value exceeds [`MAX_MICROCREDITS_SUPPLY`].
+    pub fn from_str_in(s: &str, denomination: Denomination) -> Result<Self, AmountError> {
+        let (is_negative, unsigned_str) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s),
+        };
+
+        if unsigned_str.is_empty() {
+            return Err(AmountError::InvalidString(s.to_string()));
+        }
+
+        let decimal_places = denomination.decimal_places();
+        let (integer_str, fraction_str) = match unsigned_str.split_once('.') {
+            Some((integer, fraction)) => (integer, fraction),
+            None => (unsigned_str, ""),
+        };
+
+        if fraction_str.len() > decimal_places as usize {
+            return Err(AmountError::TooPrecise(s.to_string()));
+        }
+        if !integer_str.chars().all(|c| c.is_ascii_digit())
+            || !fraction_str.chars().all(|c| c.is_ascii_digit())
+            || (integer_str.is_empty() && fraction_str.is_empty())
+        {
+            return Err(AmountError::InvalidString(s.to_string()));
+        }
+
+        let integer_value: i64 =
+            if integer_str.is_empty() { 0 } else { integer_str.parse().map_err(|_| AmountError::AmountOverflow)? };
+        let scale = 10u32.pow(decimal_places) as i64;
+        let padded_fraction = format!("{fraction_str:0<width$}", width = decimal_places as usize);
+        let fraction_value: i64 =
+            if padded_fraction.is_empty() { 0 } else { padded_fraction.parse().map_err(|_| AmountError::AmountOverflow)? };
+
+        let integer_microcredits = integer_value.checked_mul(scale).ok_or(AmountError::AmountOverflow)?;
+        let magnitude = integer_microcredits.checked_add(fraction_value).ok_or(AmountError::AmountOverflow)?;
+        let value = if is_negative { -magnitude } else { magnitude };
+
+        if !(Self::MIN.0..=Self::MAX.0).contains(&value) {
+            return Err(AmountError::AmountOverflow);
+        }
+
+        Ok(Self(value))
+    }
+}
+
+impl fmt::Display for Amount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_string_in(Denomination::MicroCredits))
+    }
+}
+
+impl FromStr for Amount {
+    type Err = AmountError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_str_in(s, Denomination::MicroCredits)
+    }
+}
+
+impl From<i64> for Amount {
+    fn from(microcredits: i64) -> Self {
+        Self(microcredits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zero_formats_without_fraction() {
+        assert_eq!(Amount::ZERO.to_string_in(Denomination::Credits), "0");
+        assert_eq!(Amount::ZERO.to_string_in(Denomination::MicroCredits), "0");
+    }
+
+    #[test]
+    fn test_format_trims_trailing_zeros_without_dropping_significant_digits() {
+        assert_eq!(Amount::from_microcredits(1_500_000).to_string_in(Denomination::Credits), "1.5");
+        assert_eq!(Amount::from_microcredits(1_000_001).to_string_in(Denomination::Credits), "1.000001");
+        assert_eq!(Amount::from_microcredits(2_000_000).to_string_in(Denomination::Credits), "2");
+    }
+
+    #[test]
+    fn test_format_negative_values() {
+        assert_eq!(Amount::from_microcredits(-1_500_000).to_string_in(Denomination::Credits), "-1.5");
+        assert_eq!(Amount::from_microcredits(-42).to_string_in(Denomination::MicroCredits), "-42");
+    }
+
+    #[test]
+    fn test_parse_roundtrip() {
+        for s in ["0", "1", "1.5", "1.000001", "-1.5", "0.000001"] {
+            let amount = Amount::from_str_in(s, Denomination::Credits).unwrap();
+            assert_eq!(amount.to_string_in(Denomination::Credits), s);
+        }
+    }
+
+    #[test]
+    fn test_parse_rejects_too_precise_input() {
+        assert_eq!(
+            Amount::from_str_in("1.0000001", Denomination::Credits),
+            Err(AmountError::TooPrecise("1.0000001".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_invalid_string() {
+        assert!(matches!(Amount::from_str_in("abc", Denomination::Credits), Err(AmountError::InvalidString(_))));
+        assert!(matches!(Amount::from_str_in("", Denomination::Credits), Err(AmountError::InvalidString(_))));
+        assert!(matches!(Amount::from_str_in("-", Denomination::Credits), Err(AmountError::InvalidString(_))));
+    }
+
+    #[test]
+    fn test_parse_max_supply_boundary() {
+        let max_credits = format!("{}", MAX_CREDITS_SUPPLY);
+        assert_eq!(Amount::from_str_in(&max_credits, Denomination::Credits).unwrap(), Amount::MAX);
+
+        let over_max_credits = format!("{}", MAX_CREDITS_SUPPLY + 1);
+        assert_eq!(Amount::from_str_in(&over_max_credits, Denomination::Credits), Err(AmountError::AmountOverflow));
+    }
+
+    #[test]
+    fn test_checked_add_overflow() {
+        assert_eq!(Amount::MAX.checked_add(Amount::from_microcredits(1)), None);
+        assert_eq!(Amount::MIN.checked_sub(Amount::from_microcredits(1)), None);
+        assert_eq!(Amount::from_microcredits(1).checked_add(Amount::from_microcredits(2)), Some(Amount::from_microcredits(3)));
+    }
+
+    #[test]
+    fn test_saturating_add_clamps_at_boundaries() {
+        assert_eq!(Amount::MAX.saturating_add(Amount::from_microcredits(1)), Amount::MAX);
+        assert_eq!(Amount::MIN.saturating_sub(Amount::from_microcredits(1)), Amount::MIN);
+    }
+}
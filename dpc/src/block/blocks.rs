@@ -0,0 +1,36 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    block::{difficulty, header::BlockHeader},
+    network::Network,
+};
+
+/// A network-parameterized namespace for block-level chain rules, such as difficulty adjustment.
+pub struct Blocks<N: Network>(core::marker::PhantomData<N>);
+
+impl<N: Network> Blocks<N> {
+    /// Computes the difficulty target for the block that follows `recent_headers`, the most
+    /// recent headers in the chain ordered oldest to newest.
+    ///
+    /// This delegates to the windowed DigiShield v3 / Zcash-style algorithm in
+    /// [`difficulty::compute_difficulty_target`], parameterized by `N::POW_LIMIT`. Near genesis,
+    /// where the chain does not yet have enough history to fill the averaging window, the network
+    /// `POW_LIMIT` is returned, as the algorithm itself specifies.
+    pub fn compute_difficulty_target(recent_headers: &[BlockHeader<N>]) -> u64 {
+        difficulty::compute_difficulty_target(recent_headers, N::POW_LIMIT)
+    }
+}
@@ -0,0 +1,251 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+/// The number of blocks in the difficulty-adjustment averaging window.
+///
+/// Following DigiShield v3 / Zcash-style DAA, the target for the next block is derived from the
+/// mean target and the actual elapsed time over the last `DIFFICULTY_WINDOW_SIZE` blocks, rather
+/// than from a single previous block. This smooths out the oscillations that a single-sample
+/// adjustment produces under bursty hashrate.
+pub const DIFFICULTY_WINDOW_SIZE: usize = 17;
+
+/// The number of most-recent timestamps used to compute the median-time-past of a block.
+///
+/// Using the median of several timestamps (instead of a single timestamp) prevents a miner from
+/// skewing the perceived elapsed time by reporting a manipulated timestamp on a single block.
+pub const MEDIAN_TIME_PAST_WINDOW_SIZE: usize = 11;
+
+/// The targeted time, in seconds, between two consecutive blocks.
+pub const TARGET_BLOCK_TIME: i64 = 10;
+
+/// The target timespan, in seconds, for the entire difficulty-adjustment window.
+pub const TARGET_TIMESPAN: i64 = TARGET_BLOCK_TIME * DIFFICULTY_WINDOW_SIZE as i64;
+
+/// The damping factor that bounds how much the actual timespan may diverge from the target
+/// timespan in a single adjustment. A larger value yields gentler (slower) adjustments.
+pub const DAMPING_FACTOR: i64 = 4;
+
+/// The minimal header data that the windowed difficulty-adjustment algorithm needs from each
+/// block in the averaging window.
+///
+/// In the full ledger, this is implemented by `BlockHeader`; it is kept minimal here so the
+/// algorithm can be exercised and tested independently of the rest of the block format.
+pub trait DifficultyHeader {
+    /// Returns the block timestamp, as a Unix epoch timestamp (in seconds).
+    fn timestamp(&self) -> i64;
+
+    /// Returns the difficulty target of the block.
+    fn difficulty_target(&self) -> u64;
+}
+
+/// Returns the median of the given timestamps.
+///
+/// This is the "median-time-past" of the block at the end of `timestamps`, which resists
+/// timestamp manipulation by a single misbehaving miner.
+fn median_time_past(timestamps: &[i64]) -> i64 {
+    let mut sorted = timestamps.to_vec();
+    sorted.sort_unstable();
+    sorted[sorted.len() / 2]
+}
+
+/// Computes the difficulty target for the next block, given the most recent blocks in the chain,
+/// ordered from oldest to newest, and the network's `pow_limit` (the easiest allowed target).
+///
+/// This implements a windowed DigiShield v3 / Zcash-style difficulty-adjustment algorithm:
+///   1. Compute the arithmetic mean of the difficulty targets over the last
+///      [`DIFFICULTY_WINDOW_SIZE`] blocks.
+///   2. Compute the actual elapsed time as the difference between the median-time-past of the
+///      most recent block and the median-time-past of the block [`DIFFICULTY_WINDOW_SIZE`]
+///      positions back.
+///   3. Clamp the actual timespan to
+///      `[TARGET_TIMESPAN * (1 - 1/DAMPING_FACTOR), TARGET_TIMESPAN * (1 + 1/DAMPING_FACTOR)]`.
+///   4. Set `new_target = mean_target * clamped_actual_timespan / TARGET_TIMESPAN`, clamped to
+///      `pow_limit`.
+///
+/// `recent_headers` must be ordered oldest-to-newest and contain enough blocks to fill both the
+/// difficulty window and the median-time-past windows on either end of it; if there is not yet
+/// enough history (e.g. near genesis), `pow_limit` is returned.
+pub fn compute_difficulty_target<H: DifficultyHeader>(recent_headers: &[H], pow_limit: u64) -> u64 {
+    // The algorithm needs `DIFFICULTY_WINDOW_SIZE` blocks, plus `MEDIAN_TIME_PAST_WINDOW_SIZE`
+    // additional blocks of history on the far end of the window to compute its median-time-past.
+    let required_len = DIFFICULTY_WINDOW_SIZE + MEDIAN_TIME_PAST_WINDOW_SIZE;
+    if recent_headers.len() < required_len {
+        return pow_limit;
+    }
+
+    // The most recent `DIFFICULTY_WINDOW_SIZE` blocks form the averaging window.
+    let window = &recent_headers[recent_headers.len() - DIFFICULTY_WINDOW_SIZE..];
+
+    // Compute the arithmetic mean of the difficulty targets over the window.
+    let target_sum: u128 = window.iter().map(|header| header.difficulty_target() as u128).sum();
+    let mean_target = (target_sum / DIFFICULTY_WINDOW_SIZE as u128) as u64;
+
+    // Compute the median-time-past of the most recent block.
+    let end_timestamps: Vec<i64> =
+        recent_headers[recent_headers.len() - MEDIAN_TIME_PAST_WINDOW_SIZE..].iter().map(|h| h.timestamp()).collect();
+    let end_mtp = median_time_past(&end_timestamps);
+
+    // Compute the median-time-past of the block `DIFFICULTY_WINDOW_SIZE` positions back from the
+    // most recent block.
+    let start_index = recent_headers.len() - 1 - DIFFICULTY_WINDOW_SIZE;
+    let start_timestamps: Vec<i64> =
+        recent_headers[start_index + 1 - MEDIAN_TIME_PAST_WINDOW_SIZE..=start_index].iter().map(|h| h.timestamp()).collect();
+    let start_mtp = median_time_past(&start_timestamps);
+
+    // Compute the actual elapsed time, and clamp it to resist both timestamp-warp attacks and
+    // hashrate spikes.
+    let actual_timespan = end_mtp - start_mtp;
+    let min_timespan = TARGET_TIMESPAN - TARGET_TIMESPAN / DAMPING_FACTOR;
+    let max_timespan = TARGET_TIMESPAN + TARGET_TIMESPAN / DAMPING_FACTOR;
+    let clamped_timespan = actual_timespan.clamp(min_timespan, max_timespan);
+
+    // Compute the new target, and clamp it to the network's `pow_limit` before truncating back
+    // down to `u64` — otherwise a `new_target` that overflows `u64::MAX` would wrap instead of
+    // saturating, which can make the difficulty *increase* when it should decrease.
+    let new_target = (mean_target as u128 * clamped_timespan as u128) / TARGET_TIMESPAN as u128;
+    new_target.min(pow_limit as u128) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct MockHeader {
+        timestamp: i64,
+        difficulty_target: u64,
+    }
+
+    impl DifficultyHeader for MockHeader {
+        fn timestamp(&self) -> i64 {
+            self.timestamp
+        }
+
+        fn difficulty_target(&self) -> u64 {
+            self.difficulty_target
+        }
+    }
+
+    const POW_LIMIT: u64 = u64::MAX / 2;
+
+    /// Builds `count` consecutive mock headers, starting at `start_timestamp`, each separated by
+    /// `block_time` seconds and sharing the given `difficulty_target`.
+    fn build_headers(start_timestamp: i64, block_time: i64, difficulty_target: u64, count: usize) -> Vec<MockHeader> {
+        (0..count)
+            .map(|i| MockHeader { timestamp: start_timestamp + (i as i64) * block_time, difficulty_target })
+            .collect()
+    }
+
+    #[test]
+    fn test_steady_state_keeps_target_stable() {
+        // With blocks arriving exactly on the target cadence, the difficulty target should not change.
+        let headers = build_headers(0, TARGET_BLOCK_TIME, 1_000_000, DIFFICULTY_WINDOW_SIZE + MEDIAN_TIME_PAST_WINDOW_SIZE);
+        let target = compute_difficulty_target(&headers, POW_LIMIT);
+        assert_eq!(target, 1_000_000);
+    }
+
+    #[test]
+    fn test_sudden_hashrate_spike_tightens_target() {
+        // Blocks arriving much faster than the target cadence indicate a hashrate spike; the next
+        // target should decrease (get harder) but no more than the damping factor allows.
+        let fast_block_time = TARGET_BLOCK_TIME / 10;
+        let headers = build_headers(0, fast_block_time, 1_000_000, DIFFICULTY_WINDOW_SIZE + MEDIAN_TIME_PAST_WINDOW_SIZE);
+        let target = compute_difficulty_target(&headers, POW_LIMIT);
+        assert!(target < 1_000_000);
+        // The timespan is clamped, so the target can shrink by at most a factor of
+        // `1 - 1 / DAMPING_FACTOR`.
+        let min_target = 1_000_000 * (DAMPING_FACTOR - 1) as u64 / DAMPING_FACTOR as u64;
+        assert!(target >= min_target);
+    }
+
+    #[test]
+    fn test_sudden_hashrate_drop_loosens_target() {
+        // Blocks arriving much slower than the target cadence indicate a hashrate drop; the next
+        // target should increase (get easier) but no more than the damping factor allows.
+        let slow_block_time = TARGET_BLOCK_TIME * 10;
+        let headers = build_headers(0, slow_block_time, 1_000_000, DIFFICULTY_WINDOW_SIZE + MEDIAN_TIME_PAST_WINDOW_SIZE);
+        let target = compute_difficulty_target(&headers, POW_LIMIT);
+        assert!(target > 1_000_000);
+        let max_target = 1_000_000 * (DAMPING_FACTOR + 1) as u64 / DAMPING_FACTOR as u64;
+        assert!(target <= max_target);
+    }
+
+    #[test]
+    fn test_timestamp_warp_attack_is_bounded() {
+        // A miner who reports a wildly manipulated timestamp on only the single most recent block
+        // should not be able to move the median-time-past by more than the other timestamps in
+        // the median-time-past window allow.
+        let mut headers = build_headers(0, TARGET_BLOCK_TIME, 1_000_000, DIFFICULTY_WINDOW_SIZE + MEDIAN_TIME_PAST_WINDOW_SIZE);
+        let honest_target = compute_difficulty_target(&headers, POW_LIMIT);
+
+        // Warp only the very last timestamp far into the future.
+        let last = headers.len() - 1;
+        headers[last].timestamp += 1_000_000;
+        let warped_target = compute_difficulty_target(&headers, POW_LIMIT);
+
+        // The median-time-past is resistant to a single outlier, so the resulting target should
+        // still be clamped to the same bounds as the honest case.
+        assert_eq!(honest_target, warped_target);
+    }
+
+    #[test]
+    fn test_insufficient_history_returns_pow_limit() {
+        let headers = build_headers(0, TARGET_BLOCK_TIME, 1_000_000, 3);
+        let target = compute_difficulty_target(&headers, POW_LIMIT);
+        assert_eq!(target, POW_LIMIT);
+    }
+
+    #[test]
+    fn test_one_short_of_required_history_does_not_panic() {
+        // One block short of `required_len` must gracefully fall back to `pow_limit`, not panic.
+        let headers =
+            build_headers(0, TARGET_BLOCK_TIME, 1_000_000, DIFFICULTY_WINDOW_SIZE + MEDIAN_TIME_PAST_WINDOW_SIZE - 1);
+        let target = compute_difficulty_target(&headers, POW_LIMIT);
+        assert_eq!(target, POW_LIMIT);
+    }
+
+    #[test]
+    fn test_exact_required_history_does_not_panic() {
+        // Exactly `required_len` blocks of history is the minimum the function claims to support,
+        // and must not panic.
+        let headers =
+            build_headers(0, TARGET_BLOCK_TIME, 1_000_000, DIFFICULTY_WINDOW_SIZE + MEDIAN_TIME_PAST_WINDOW_SIZE);
+        let target = compute_difficulty_target(&headers, POW_LIMIT);
+        assert_eq!(target, 1_000_000);
+    }
+
+    #[test]
+    fn test_new_target_is_clamped_to_pow_limit() {
+        // An extreme hashrate drop should never push the next target past the network's `pow_limit`.
+        let slow_block_time = TARGET_BLOCK_TIME * 1_000;
+        let headers = build_headers(0, slow_block_time, POW_LIMIT, DIFFICULTY_WINDOW_SIZE + MEDIAN_TIME_PAST_WINDOW_SIZE);
+        let target = compute_difficulty_target(&headers, POW_LIMIT);
+        assert_eq!(target, POW_LIMIT);
+    }
+
+    #[test]
+    fn test_large_mean_target_does_not_overflow_u64_before_clamping() {
+        // With a `pow_limit` close to `u64::MAX` and the timespan clamped to its maximum (+25%),
+        // `mean_target * clamped_timespan / TARGET_TIMESPAN` can exceed `u64::MAX` before being
+        // clamped down to `pow_limit`; the clamp must happen in `u128` before the final cast, or
+        // the result silently wraps into a much smaller (harder) target.
+        let pow_limit = (u64::MAX / 10) * 9; // ~0.9 * u64::MAX
+        let slow_block_time = TARGET_BLOCK_TIME * 10;
+        let headers =
+            build_headers(0, slow_block_time, pow_limit, DIFFICULTY_WINDOW_SIZE + MEDIAN_TIME_PAST_WINDOW_SIZE);
+        let target = compute_difficulty_target(&headers, pow_limit);
+        assert_eq!(target, pow_limit);
+    }
+}
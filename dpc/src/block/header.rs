@@ -0,0 +1,217 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    block::{
+        difficulty::DifficultyHeader,
+        pow::equihash,
+    },
+    network::Network,
+};
+
+use rand::{CryptoRng, Rng};
+use std::marker::PhantomData;
+
+/// The maximum number of nonces a single `BlockHeader::new` call will try before giving up. In
+/// practice a solution is found within a handful of attempts; this only guards against spinning
+/// forever if the Equihash parameters were ever misconfigured.
+const MAX_MINING_ATTEMPTS: u64 = 1_000_000;
+
+/// An error that can occur while constructing or validating a [`BlockHeader`].
+#[derive(Debug, PartialEq, Eq)]
+pub enum BlockHeaderError {
+    /// No Equihash solution was found within [`MAX_MINING_ATTEMPTS`] nonces.
+    ProofOfWorkNotFound,
+}
+
+impl std::fmt::Display for BlockHeaderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ProofOfWorkNotFound => {
+                write!(f, "failed to find an Equihash solution within {MAX_MINING_ATTEMPTS} attempts")
+            }
+        }
+    }
+}
+
+impl std::error::Error for BlockHeaderError {}
+
+/// A block header, carrying the block's metadata, its commitments to the block's transactions and
+/// the ledger's serial number / commitment sets, and the Equihash proof of work that binds it all
+/// together.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BlockHeader<N: Network> {
+    height: u32,
+    timestamp: i64,
+    difficulty_target: u64,
+    transactions_root: [u8; 32],
+    serial_numbers_root: [u8; 32],
+    commitments_root: [u8; 32],
+    /// The nonce that, combined with the other fields, produced a header commitment for which
+    /// `proof` is a valid Equihash solution.
+    nonce: u64,
+    /// The Equihash(`N::EQUIHASH_N`, `N::EQUIHASH_K`) solution proving the header was mined.
+    proof: Vec<u32>,
+    _network: PhantomData<N>,
+}
+
+impl<N: Network> BlockHeader<N> {
+    /// Constructs a new block header and mines its proof of work, using `rng` to randomize the
+    /// starting nonce.
+    pub fn new<R: Rng + CryptoRng>(
+        height: u32,
+        timestamp: i64,
+        difficulty_target: u64,
+        transactions_root: [u8; 32],
+        serial_numbers_root: [u8; 32],
+        commitments_root: [u8; 32],
+        rng: &mut R,
+    ) -> Result<Self, BlockHeaderError> {
+        let starting_nonce: u64 = rng.gen();
+
+        for attempt in 0..MAX_MINING_ATTEMPTS {
+            let nonce = starting_nonce.wrapping_add(attempt);
+            let commitment = Self::header_commitment(
+                height,
+                timestamp,
+                difficulty_target,
+                &transactions_root,
+                &serial_numbers_root,
+                &commitments_root,
+                nonce,
+            );
+
+            if let Some(proof) = equihash::solve::<N>(&commitment, N::EQUIHASH_PERSONALIZATION) {
+                return Ok(Self {
+                    height,
+                    timestamp,
+                    difficulty_target,
+                    transactions_root,
+                    serial_numbers_root,
+                    commitments_root,
+                    nonce,
+                    proof,
+                    _network: PhantomData,
+                });
+            }
+        }
+
+        Err(BlockHeaderError::ProofOfWorkNotFound)
+    }
+
+    /// Returns the block height this header commits to.
+    pub const fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Returns the block's Unix epoch timestamp, in seconds.
+    pub const fn timestamp(&self) -> i64 {
+        self.timestamp
+    }
+
+    /// Returns the proof-of-work difficulty target this header was mined against.
+    pub const fn difficulty_target(&self) -> u64 {
+        self.difficulty_target
+    }
+
+    /// Returns the Equihash(`N::EQUIHASH_N`, `N::EQUIHASH_K`) proof of work bound to this header.
+    pub fn proof(&self) -> &[u32] {
+        &self.proof
+    }
+
+    /// Returns `true` if this header's Equihash proof is valid for its committed fields.
+    ///
+    /// Note: this checks the proof of work only; it does not re-derive `difficulty_target` from
+    /// the chain's history (see [`crate::block::blocks::Blocks::compute_difficulty_target`]) or
+    /// check that `proof` actually meets `difficulty_target`, both of which are the caller's
+    /// responsibility as part of full block validation.
+    pub fn is_valid(&self) -> bool {
+        let commitment = Self::header_commitment(
+            self.height,
+            self.timestamp,
+            self.difficulty_target,
+            &self.transactions_root,
+            &self.serial_numbers_root,
+            &self.commitments_root,
+            self.nonce,
+        );
+        equihash::verify::<N>(&commitment, N::EQUIHASH_PERSONALIZATION, &self.proof)
+    }
+
+    /// Derives the byte string that the Equihash puzzle is solved/verified against: the
+    /// concatenation of every field a header commits to, other than the proof itself.
+    fn header_commitment(
+        height: u32,
+        timestamp: i64,
+        difficulty_target: u64,
+        transactions_root: &[u8; 32],
+        serial_numbers_root: &[u8; 32],
+        commitments_root: &[u8; 32],
+        nonce: u64,
+    ) -> Vec<u8> {
+        let mut commitment = Vec::with_capacity(4 + 8 + 8 + 32 * 3 + 8);
+        commitment.extend_from_slice(&height.to_le_bytes());
+        commitment.extend_from_slice(&timestamp.to_le_bytes());
+        commitment.extend_from_slice(&difficulty_target.to_le_bytes());
+        commitment.extend_from_slice(transactions_root);
+        commitment.extend_from_slice(serial_numbers_root);
+        commitment.extend_from_slice(commitments_root);
+        commitment.extend_from_slice(&nonce.to_le_bytes());
+        commitment
+    }
+}
+
+impl<N: Network> DifficultyHeader for BlockHeader<N> {
+    fn timestamp(&self) -> i64 {
+        self.timestamp
+    }
+
+    fn difficulty_target(&self) -> u64 {
+        self.difficulty_target
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{block::blocks::Blocks, network::Testnet2};
+    use rand::thread_rng;
+
+    #[test]
+    fn test_new_header_mines_a_valid_proof() {
+        let header =
+            BlockHeader::<Testnet2>::new(1, 0, Testnet2::POW_LIMIT, [1u8; 32], [2u8; 32], [3u8; 32], &mut thread_rng())
+                .unwrap();
+        assert!(header.is_valid());
+    }
+
+    #[test]
+    fn test_tampered_header_fails_validation() {
+        let mut header =
+            BlockHeader::<Testnet2>::new(1, 0, Testnet2::POW_LIMIT, [1u8; 32], [2u8; 32], [3u8; 32], &mut thread_rng())
+                .unwrap();
+        header.timestamp = 1234;
+        assert!(!header.is_valid());
+    }
+
+    #[test]
+    fn test_blocks_compute_difficulty_target_falls_back_to_pow_limit_near_genesis() {
+        let header =
+            BlockHeader::<Testnet2>::new(1, 0, Testnet2::POW_LIMIT, [1u8; 32], [2u8; 32], [3u8; 32], &mut thread_rng())
+                .unwrap();
+        assert_eq!(Blocks::<Testnet2>::compute_difficulty_target(&[header]), Testnet2::POW_LIMIT);
+    }
+}
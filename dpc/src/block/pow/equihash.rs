@@ -0,0 +1,334 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! An Equihash(n, k) memory-hard proof-of-work, as used by Zcash.
+//!
+//! A solution is a set of `2^k` distinct indices into a list of `2^((n/(k+1))+1)` BLAKE2b-derived
+//! `n`-bit digests, such that XOR-ing together the digests at those indices yields all zero bits,
+//! and the indices obey the ordering rule imposed by Wagner's algorithm (see [`is_valid_ordering`]).
+
+use blake2::{
+    digest::{Update, VariableOutput},
+    Blake2bVar,
+};
+
+/// The Equihash parameters for a given network: the digest length `n` (in bits) and the number of
+/// collision rounds `k`. `n` must be a multiple of `k + 1`.
+pub trait EquihashParameters {
+    /// The bit length of each indexed digest.
+    const N: u32;
+    /// The number of Wagner's-algorithm collision rounds.
+    const K: u32;
+
+    /// The number of bits collided on in each round, i.e. `n / (k + 1)`.
+    fn collision_bit_length() -> u32 {
+        Self::N / (Self::K + 1)
+    }
+
+    /// The number of indexed digests in the initial list, i.e. `2^(collision_bit_length + 1)`.
+    fn list_length() -> usize {
+        1usize << (Self::collision_bit_length() + 1)
+    }
+
+    /// The number of indices in a valid solution, i.e. `2^k`.
+    fn solution_length() -> usize {
+        1usize << Self::K
+    }
+}
+
+/// A single entry produced by Wagner's algorithm: a (partial) digest together with the set of
+/// original list indices that were XOR-ed together to produce it.
+#[derive(Clone, Debug)]
+struct Entry {
+    digest: Vec<u8>,
+    indices: Vec<u32>,
+}
+
+/// Derives the initial list of `2^((n/(k+1))+1)` indexed digests by hashing `personalization ||
+/// header_commitment || index` with BLAKE2b, for each `index` in the list.
+fn generate_initial_list<P: EquihashParameters>(header_commitment: &[u8], personalization: &[u8]) -> Vec<Entry> {
+    let digest_bytes = P::N.div_ceil(8) as usize;
+
+    (0..P::list_length() as u32)
+        .map(|index| {
+            let mut hasher = Blake2bVar::new(digest_bytes).expect("digest length must be valid for BLAKE2b");
+            hasher.update(personalization);
+            hasher.update(header_commitment);
+            hasher.update(&index.to_le_bytes());
+            let mut digest = vec![0u8; digest_bytes];
+            hasher.finalize_variable(&mut digest).expect("BLAKE2b finalization should not fail");
+            Entry { digest, indices: vec![index] }
+        })
+        .collect()
+}
+
+/// Returns `num_bits` of `digest`, as a big-endian integer, starting `bit_offset` bits in from the
+/// front. This is the sort/collision key used for a given round: round `i` collides on the bits
+/// that round `i - 1`'s collisions left unexamined, sliding `collision_bit_length` bits deeper
+/// into the digest each round.
+fn digest_bits(digest: &[u8], bit_offset: u32, num_bits: u32) -> u128 {
+    let mut value = 0u128;
+    let mut bits_remaining = num_bits;
+    let mut bits_to_skip = bit_offset;
+    for &byte in digest {
+        if bits_remaining == 0 {
+            break;
+        }
+        if bits_to_skip >= 8 {
+            bits_to_skip -= 8;
+            continue;
+        }
+        let available = 8 - bits_to_skip;
+        let take = bits_remaining.min(available);
+        let shifted = ((byte as u128) >> (available - take)) & ((1u128 << take) - 1);
+        value = (value << take) | shifted;
+        bits_remaining -= take;
+        bits_to_skip = 0;
+    }
+    value
+}
+
+/// XORs two digests of equal length.
+fn xor_digests(a: &[u8], b: &[u8]) -> Vec<u8> {
+    a.iter().zip(b.iter()).map(|(x, y)| x ^ y).collect()
+}
+
+/// Solves the Equihash(n, k) puzzle for the given header commitment, using Wagner's algorithm.
+///
+/// Over `k` rounds, entries are sorted by the next `collision_bit_length` bits of their digest
+/// (sliding deeper into the digest each round), grouping colliding entries into contiguous
+/// buckets; every pairwise combination within a bucket is XOR-ed together to form the next
+/// round's entries, carrying forward the union of their index sets. Pairing every combination
+/// (rather than only adjacent pairs) matters because buckets routinely hold more than two entries
+/// at realistic parameters (the list size is chosen so that buckets hold ~2 entries on average,
+/// which means many hold three or more) — pairing only adjacent entries would silently discard
+/// the rest of the bucket and make finding a solution exponentially less likely over `k` rounds.
+/// Because colliding segments XOR to zero, after `k` rounds a surviving entry has matched on
+/// `k * collision_bit_length` of its `n` bits; if its remaining bits also happen to be zero, it is
+/// a solution of exactly `2^k` distinct indices.
+///
+/// Returns `None` if no solution is found (the caller should retry with a different nonce/seed).
+pub fn solve<P: EquihashParameters>(header_commitment: &[u8], personalization: &[u8]) -> Option<Vec<u32>> {
+    let mut entries = generate_initial_list::<P>(header_commitment, personalization);
+    let collision_bits = P::collision_bit_length();
+
+    for round in 0..P::K {
+        let bit_offset = round * collision_bits;
+
+        // Sort entries by the next `collision_bits` of their digest so that colliding entries fall
+        // into contiguous buckets.
+        entries.sort_by_key(|entry| digest_bits(&entry.digest, bit_offset, collision_bits));
+
+        let mut next_round = Vec::new();
+        let mut bucket_start = 0;
+        while bucket_start < entries.len() {
+            let key = digest_bits(&entries[bucket_start].digest, bit_offset, collision_bits);
+            let mut bucket_end = bucket_start + 1;
+            while bucket_end < entries.len()
+                && digest_bits(&entries[bucket_end].digest, bit_offset, collision_bits) == key
+            {
+                bucket_end += 1;
+            }
+
+            // Pair every combination of entries within this bucket, not just adjacent ones.
+            for x in bucket_start..bucket_end {
+                for y in (x + 1)..bucket_end {
+                    let a = &entries[x];
+                    let b = &entries[y];
+
+                    // Only combine entries that do not already share an index.
+                    if a.indices.iter().all(|idx| !b.indices.contains(idx)) {
+                        let digest = xor_digests(&a.digest, &b.digest);
+                        // Canonical Wagner's-algorithm ordering: the sub-tree whose first
+                        // (smallest, since each sub-tree's indices are themselves already
+                        // canonically ordered) index is lower is always placed on the left. This
+                        // is exactly the ordering [`is_valid_ordering`] checks for at verification
+                        // time.
+                        let (left, right) = if a.indices[0] < b.indices[0] { (a, b) } else { (b, a) };
+                        let mut indices = left.indices.clone();
+                        indices.extend(right.indices.iter().copied());
+                        next_round.push(Entry { digest, indices });
+                    }
+                }
+            }
+
+            bucket_start = bucket_end;
+        }
+
+        entries = next_round;
+        if entries.is_empty() {
+            return None;
+        }
+    }
+
+    entries
+        .into_iter()
+        .find(|entry| entry.digest.iter().all(|&byte| byte == 0) && entry.indices.len() == P::solution_length())
+        .map(|entry| entry.indices)
+}
+
+/// Verifies that `indices` is a valid Equihash(n, k) solution for the given header commitment.
+///
+/// A solution is valid if and only if:
+///   1. The indices obey the ordering rule imposed by Wagner's algorithm (see
+///      [`is_valid_ordering`]) — this binds the solution to a specific sequence of collisions
+///      rather than an arbitrary zero-XOR-ing subset.
+///   2. All `2^k` indices are distinct.
+///   3. Recomputing the initial digests at those indices and XOR-ing them together yields zero.
+pub fn verify<P: EquihashParameters>(header_commitment: &[u8], personalization: &[u8], indices: &[u32]) -> bool {
+    if indices.len() != P::solution_length() {
+        return false;
+    }
+
+    // Rule 1: the binding ordering rule.
+    if !is_valid_ordering(indices) {
+        return false;
+    }
+
+    // Rule 2: all indices distinct.
+    let mut sorted_indices = indices.to_vec();
+    sorted_indices.sort_unstable();
+    if sorted_indices.windows(2).any(|pair| pair[0] == pair[1]) {
+        return false;
+    }
+
+    // Rule 3: the XOR of the indexed digests must be all-zero.
+    let digest_bytes = P::N.div_ceil(8) as usize;
+    let mut xor = vec![0u8; digest_bytes];
+    for &index in indices {
+        let mut hasher = Blake2bVar::new(digest_bytes).expect("digest length must be valid for BLAKE2b");
+        hasher.update(personalization);
+        hasher.update(header_commitment);
+        hasher.update(&index.to_le_bytes());
+        let mut digest = vec![0u8; digest_bytes];
+        hasher.finalize_variable(&mut digest).expect("BLAKE2b finalization should not fail");
+        xor = xor_digests(&xor, &digest);
+    }
+
+    xor.iter().all(|&byte| byte == 0)
+}
+
+/// Checks the ordering rule that a valid Equihash solution's indices must satisfy.
+///
+/// Wagner's algorithm builds a binary tree of XOR-ed pairs: at each internal node, the left
+/// child's first (and, inductively, smallest) index must be less than the right child's first
+/// index. This binds a solution to one specific sequence of collisions — the one the solver
+/// actually took — rather than to an arbitrary zero-XOR-ing subset of indices, and is checked
+/// recursively over sub-trees of size `2^i`, for `i` from 1 up to `k`.
+fn is_valid_ordering(indices: &[u32]) -> bool {
+    fn check(indices: &[u32]) -> bool {
+        if indices.len() <= 1 {
+            return true;
+        }
+        let half = indices.len() / 2;
+        let (left, right) = indices.split_at(half);
+        left[0] < right[0] && check(left) && check(right)
+    }
+    check(indices)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Tiny Equihash parameters (n=24, k=3), chosen to make brute-force solving fast in tests.
+    struct TestParameters;
+    impl EquihashParameters for TestParameters {
+        const N: u32 = 24;
+        const K: u32 = 3;
+    }
+
+    const PERSONALIZATION: &[u8] = b"AleoPoW";
+
+    /// Mines a header commitment (by appending an incrementing nonce to `base_commitment`) until
+    /// a solution is found, as a real miner would. Returns the mined commitment and its solution.
+    fn mine<P: EquihashParameters>(base_commitment: &[u8]) -> (Vec<u8>, Vec<u32>) {
+        for nonce in 0u32.. {
+            let mut commitment = base_commitment.to_vec();
+            commitment.extend_from_slice(&nonce.to_le_bytes());
+            if let Some(solution) = solve::<P>(&commitment, PERSONALIZATION) {
+                return (commitment, solution);
+            }
+        }
+        unreachable!("a solution should be found within a reasonable number of nonces")
+    }
+
+    #[test]
+    fn test_solve_and_verify_roundtrip() {
+        let (commitment, solution) = mine::<TestParameters>(b"test header commitment");
+        assert_eq!(solution.len(), TestParameters::solution_length());
+        assert!(verify::<TestParameters>(&commitment, PERSONALIZATION, &solution));
+    }
+
+    #[test]
+    fn test_solve_finds_solutions_when_buckets_hold_more_than_two_entries() {
+        // Parameters with more rounds (`k=4`) and a small list (32 entries over 16 buckets, for
+        // an average bucket occupancy of 2) make it common for a collision bucket in any given
+        // round to hold three or more entries. Only pairing adjacent sorted entries (instead of
+        // every combination within a bucket) silently drops the rest of such buckets, and that
+        // loss compounds multiplicatively over `k` rounds — so with this many rounds, a solution
+        // should still be found within a small, bounded number of nonce attempts.
+        struct WideBucketParameters;
+        impl EquihashParameters for WideBucketParameters {
+            const N: u32 = 20;
+            const K: u32 = 4;
+        }
+
+        const MAX_ATTEMPTS: u32 = 2_000;
+        let mut solution = None;
+        for nonce in 0..MAX_ATTEMPTS {
+            let mut commitment = b"wide bucket test".to_vec();
+            commitment.extend_from_slice(&nonce.to_le_bytes());
+            if let Some(found) = solve::<WideBucketParameters>(&commitment, PERSONALIZATION) {
+                solution = Some((commitment, found));
+                break;
+            }
+        }
+
+        let (commitment, solution) =
+            solution.unwrap_or_else(|| panic!("no solution found within {MAX_ATTEMPTS} attempts"));
+        assert_eq!(solution.len(), WideBucketParameters::solution_length());
+        assert!(verify::<WideBucketParameters>(&commitment, PERSONALIZATION, &solution));
+    }
+
+    #[test]
+    fn test_verify_rejects_duplicate_indices() {
+        let (commitment, mut solution) = mine::<TestParameters>(b"test header commitment");
+        solution[1] = solution[0];
+        assert!(!verify::<TestParameters>(&commitment, PERSONALIZATION, &solution));
+    }
+
+    #[test]
+    fn test_verify_rejects_bad_ordering() {
+        let (commitment, mut solution) = mine::<TestParameters>(b"test header commitment");
+        let last = solution.len() - 1;
+        solution.swap(0, last);
+        assert!(!is_valid_ordering(&solution) || !verify::<TestParameters>(&commitment, PERSONALIZATION, &solution));
+    }
+
+    #[test]
+    fn test_verify_rejects_wrong_commitment() {
+        let (_commitment, solution) = mine::<TestParameters>(b"test header commitment");
+        assert!(!verify::<TestParameters>(b"a different commitment", PERSONALIZATION, &solution));
+    }
+
+    #[test]
+    fn test_verify_rejects_wrong_length() {
+        let (commitment, mut solution) = mine::<TestParameters>(b"test header commitment");
+        solution.pop();
+        assert!(!verify::<TestParameters>(&commitment, PERSONALIZATION, &solution));
+    }
+}
@@ -0,0 +1,64 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{amount::Amount, network::Network};
+
+/// The coinbase reward paid to the miner of the genesis block, and of every block until the
+/// first halving.
+const STARTING_BLOCK_REWARD: Amount = Amount::from_microcredits(50 * crate::amount::MICROCREDITS_PER_CREDIT);
+
+/// The number of blocks between successive reward halvings.
+const BLOCK_REWARD_HALVING_INTERVAL: u32 = 1_000_000;
+
+/// A namespace for block-level constants and derivations, parameterized by [`Network`].
+pub struct Block<N: Network>(core::marker::PhantomData<N>);
+
+impl<N: Network> Block<N> {
+    /// Returns the coinbase reward for the block at `height`, halving every
+    /// [`BLOCK_REWARD_HALVING_INTERVAL`] blocks until it reaches zero.
+    pub fn block_reward(height: u32) -> Amount {
+        let halvings = height / BLOCK_REWARD_HALVING_INTERVAL;
+        if halvings >= i64::BITS {
+            return Amount::ZERO;
+        }
+        Amount::from_microcredits(STARTING_BLOCK_REWARD.as_microcredits() >> halvings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network::Testnet2;
+
+    #[test]
+    fn test_genesis_reward_is_the_starting_reward() {
+        assert_eq!(Block::<Testnet2>::block_reward(0), STARTING_BLOCK_REWARD);
+        assert_eq!(Block::<Testnet2>::block_reward(1), STARTING_BLOCK_REWARD);
+    }
+
+    #[test]
+    fn test_reward_halves_at_the_halving_interval() {
+        assert_eq!(
+            Block::<Testnet2>::block_reward(BLOCK_REWARD_HALVING_INTERVAL),
+            Amount::from_microcredits(STARTING_BLOCK_REWARD.as_microcredits() / 2)
+        );
+    }
+
+    #[test]
+    fn test_reward_eventually_reaches_zero() {
+        assert_eq!(Block::<Testnet2>::block_reward(BLOCK_REWARD_HALVING_INTERVAL * 64), Amount::ZERO);
+    }
+}
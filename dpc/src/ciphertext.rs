@@ -0,0 +1,151 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    account::{view_key, ViewKey},
+    network::Network,
+    record::Record,
+};
+
+use blake2::{
+    digest::{Update, VariableOutput},
+    Blake2bVar,
+};
+use rand::{CryptoRng, Rng};
+use std::marker::PhantomData;
+
+/// An error that can occur while decrypting a [`Ciphertext`].
+#[derive(Debug, PartialEq, Eq)]
+pub enum CiphertextError {
+    /// The view key used to decrypt did not match the ciphertext's recipient.
+    DecryptionFailed,
+}
+
+impl std::fmt::Display for CiphertextError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::DecryptionFailed => write!(f, "view key does not match this ciphertext's recipient"),
+        }
+    }
+}
+
+impl std::error::Error for CiphertextError {}
+
+/// A symmetrically encrypted [`Record`], decryptable by the recipient address's [`ViewKey`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Ciphertext<N: Network> {
+    /// A random nonce, mixed into the keystream so that encrypting the same record twice does
+    /// not produce identical ciphertexts.
+    nonce: [u8; 16],
+    owner_tag: [u8; 32],
+    bytes: [u8; 40],
+    _network: PhantomData<N>,
+}
+
+impl<N: Network> Ciphertext<N> {
+    /// Encrypts `record` under the key derived from `record.owner()`'s address, which is the
+    /// value of that address's view key. Because this key is computed from the public address
+    /// alone (see [`view_key::RECORD_KEY_DOMAIN`]), anyone who knows the address can decrypt it.
+    pub fn encrypt<R: Rng + CryptoRng>(record: Record<N>, rng: &mut R) -> Self {
+        let mut nonce = [0u8; 16];
+        rng.fill(&mut nonce);
+
+        let record_key = view_key::record_key(&record.owner().to_bytes());
+        Self {
+            nonce,
+            owner_tag: owner_tag(&record_key, &nonce),
+            bytes: xor_keystream(&record_key, &nonce, record.to_bytes()),
+            _network: PhantomData,
+        }
+    }
+
+    /// Decrypts this ciphertext with `view_key`, returning an error if `view_key` does not
+    /// correspond to the address this ciphertext was encrypted for.
+    pub fn decrypt(&self, view_key: &ViewKey<N>) -> Result<Record<N>, CiphertextError> {
+        let record_key = view_key.to_bytes();
+        if owner_tag(&record_key, &self.nonce) != self.owner_tag {
+            return Err(CiphertextError::DecryptionFailed);
+        }
+        Ok(Record::from_bytes(xor_keystream(&record_key, &self.nonce, self.bytes)))
+    }
+}
+
+/// A short, publicly-visible tag derived from the record key and nonce, letting
+/// [`Ciphertext::decrypt`] reject a mismatched view key instead of silently returning garbage.
+fn owner_tag(record_key: &[u8; 32], nonce: &[u8; 16]) -> [u8; 32] {
+    let mut hasher = Blake2bVar::new(32).expect("32 is a valid BLAKE2b digest length");
+    hasher.update(b"AleoOwnerTag");
+    hasher.update(record_key);
+    hasher.update(nonce);
+    let mut output = [0u8; 32];
+    hasher.finalize_variable(&mut output).expect("BLAKE2b finalization should not fail");
+    output
+}
+
+/// XORs `data` with a keystream expanded from `record_key` and `nonce` via BLAKE2b, long enough
+/// to cover `data`'s length.
+fn xor_keystream(record_key: &[u8; 32], nonce: &[u8; 16], data: [u8; 40]) -> [u8; 40] {
+    let mut hasher = Blake2bVar::new(40).expect("40 is a valid BLAKE2b digest length");
+    hasher.update(b"AleoRecordKeystream");
+    hasher.update(record_key);
+    hasher.update(nonce);
+    let mut keystream = [0u8; 40];
+    hasher.finalize_variable(&mut keystream).expect("BLAKE2b finalization should not fail");
+
+    let mut output = [0u8; 40];
+    for i in 0..40 {
+        output[i] = data[i] ^ keystream[i];
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{account::Account, amount::Amount, network::Testnet2};
+    use rand::thread_rng;
+
+    #[test]
+    fn test_encrypt_decrypt_roundtrip() {
+        let account = Account::<Testnet2>::new(&mut thread_rng());
+        let record = Record::new(account.address(), Amount::from_credits(50));
+
+        let ciphertext = Ciphertext::encrypt(record, &mut thread_rng());
+        let decrypted = ciphertext.decrypt(account.view_key()).unwrap();
+
+        assert_eq!(decrypted.owner(), record.owner());
+        assert_eq!(decrypted.value(), record.value());
+    }
+
+    #[test]
+    fn test_decrypt_rejects_wrong_view_key() {
+        let account = Account::<Testnet2>::new(&mut thread_rng());
+        let other_account = Account::<Testnet2>::new(&mut thread_rng());
+        let record = Record::new(account.address(), Amount::from_credits(50));
+
+        let ciphertext = Ciphertext::encrypt(record, &mut thread_rng());
+        assert_eq!(ciphertext.decrypt(other_account.view_key()), Err(CiphertextError::DecryptionFailed));
+    }
+
+    #[test]
+    fn test_encrypting_the_same_record_twice_yields_different_ciphertexts() {
+        let account = Account::<Testnet2>::new(&mut thread_rng());
+        let record = Record::new(account.address(), Amount::from_credits(50));
+
+        let mut rng = thread_rng();
+        assert_ne!(Ciphertext::encrypt(record, &mut rng), Ciphertext::encrypt(record, &mut rng));
+    }
+}
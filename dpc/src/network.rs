@@ -0,0 +1,94 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The network-level constants that parameterize proof-of-work difficulty, the Equihash puzzle,
+//! and the Bech32m human-readable prefixes used by account encodings. Each concrete network (e.g.
+//! [`Testnet2`]) is a zero-sized marker type that pins these constants; generic code is written
+//! once against the [`Network`] trait and instantiated per network.
+
+use crate::block::pow::equihash::EquihashParameters;
+
+/// The network-level constants shared by every concrete network.
+pub trait Network: 'static + Copy + Clone + Eq + PartialEq {
+    /// The easiest allowed proof-of-work difficulty target.
+    const POW_LIMIT: u64;
+    /// The Equihash digest length `n`, in bits.
+    const EQUIHASH_N: u32;
+    /// The number of Equihash collision rounds `k`.
+    const EQUIHASH_K: u32;
+    /// The Equihash personalization string, domain-separating this network's puzzle from others.
+    const EQUIHASH_PERSONALIZATION: &'static [u8];
+
+    /// The Bech32m human-readable prefix for this network's account addresses.
+    const ADDRESS_HRP: &'static str;
+    /// The Bech32m human-readable prefix for this network's account view keys.
+    const VIEW_KEY_HRP: &'static str;
+    /// The Bech32m human-readable prefix for this network's account private keys.
+    const PRIVATE_KEY_HRP: &'static str;
+}
+
+/// Every [`Network`] is, by construction, also a valid parameterization of the Equihash puzzle.
+impl<N: Network> EquihashParameters for N {
+    const K: u32 = N::EQUIHASH_K;
+    const N: u32 = N::EQUIHASH_N;
+}
+
+/// The Aleo test network.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Testnet2;
+
+impl Network for Testnet2 {
+    const ADDRESS_HRP: &'static str = "aleo";
+    // Small enough that mining a solution in tests completes in milliseconds.
+    const EQUIHASH_K: u32 = 3;
+    const EQUIHASH_N: u32 = 24;
+    const EQUIHASH_PERSONALIZATION: &'static [u8] = b"AleoPoW2";
+    const POW_LIMIT: u64 = u64::MAX >> 2;
+    const PRIVATE_KEY_HRP: &'static str = "aprivatekey";
+    const VIEW_KEY_HRP: &'static str = "aviewkey";
+}
+
+/// The Aleo main network.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Mainnet;
+
+impl Network for Mainnet {
+    const ADDRESS_HRP: &'static str = "aleo";
+    // Large enough to be a genuinely memory-hard puzzle; real mainnet mining is expected to run on
+    // dedicated hardware, unlike the test network.
+    const EQUIHASH_K: u32 = 9;
+    const EQUIHASH_N: u32 = 200;
+    const EQUIHASH_PERSONALIZATION: &'static [u8] = b"AleoPoW1";
+    const POW_LIMIT: u64 = u64::MAX >> 8;
+    const PRIVATE_KEY_HRP: &'static str = "aprivatekey";
+    const VIEW_KEY_HRP: &'static str = "aviewkey";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_testnet2_and_mainnet_have_distinct_equihash_parameters() {
+        // Each network's `EquihashParameters` impl (via the blanket impl above) must reflect its
+        // own `EQUIHASH_N`/`EQUIHASH_K`, not some other network's.
+        assert_eq!(<Testnet2 as EquihashParameters>::N, Testnet2::EQUIHASH_N);
+        assert_eq!(<Testnet2 as EquihashParameters>::K, Testnet2::EQUIHASH_K);
+        assert_eq!(<Mainnet as EquihashParameters>::N, Mainnet::EQUIHASH_N);
+        assert_eq!(<Mainnet as EquihashParameters>::K, Mainnet::EQUIHASH_K);
+        assert_ne!(Testnet2::EQUIHASH_N, Mainnet::EQUIHASH_N);
+    }
+}
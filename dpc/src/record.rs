@@ -0,0 +1,63 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{account::Address, amount::Amount, network::Network};
+
+/// A record: an amount of credits owned by a particular address.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Record<N: Network> {
+    owner: Address<N>,
+    value: Amount,
+}
+
+impl<N: Network> Record<N> {
+    /// Constructs a new record for `value` credits, owned by `owner`.
+    pub const fn new(owner: Address<N>, value: Amount) -> Self {
+        Self { owner, value }
+    }
+
+    /// Returns the record's owner.
+    pub const fn owner(&self) -> Address<N> {
+        self.owner
+    }
+
+    /// Returns the record's value.
+    pub const fn value(&self) -> Amount {
+        self.value
+    }
+
+    /// Serializes the record as its owner's address bytes followed by its value, as
+    /// little-endian microcredits.
+    pub(crate) fn to_bytes(self) -> [u8; 40] {
+        let mut bytes = [0u8; 40];
+        bytes[..32].copy_from_slice(&self.owner.to_bytes());
+        bytes[32..].copy_from_slice(&self.value.as_microcredits().to_le_bytes());
+        bytes
+    }
+
+    /// Deserializes a record from the encoding produced by [`Record::to_bytes`].
+    pub(crate) fn from_bytes(bytes: [u8; 40]) -> Self {
+        let mut owner_bytes = [0u8; 32];
+        owner_bytes.copy_from_slice(&bytes[..32]);
+        let mut value_bytes = [0u8; 8];
+        value_bytes.copy_from_slice(&bytes[32..]);
+
+        Self {
+            owner: Address::from_bytes(owner_bytes),
+            value: Amount::from_microcredits(i64::from_le_bytes(value_bytes)),
+        }
+    }
+}
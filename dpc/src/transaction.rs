@@ -0,0 +1,98 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{account::Address, amount::Amount, ciphertext::Ciphertext, network::Network, record::Record};
+
+use rand::{CryptoRng, Rng};
+
+/// An error that can occur while constructing a [`Transaction`].
+#[derive(Debug, PartialEq, Eq)]
+pub enum TransactionError {
+    /// The requested amount was negative.
+    NegativeAmount(Amount),
+}
+
+impl std::fmt::Display for TransactionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NegativeAmount(amount) => write!(f, "coinbase amount must not be negative, found {amount}"),
+        }
+    }
+}
+
+impl std::error::Error for TransactionError {}
+
+/// A transaction: a set of encrypted output records.
+///
+/// Only coinbase transactions (a single newly-minted output, with no inputs to spend) are
+/// modeled here; a full transaction additionally spends existing records via serial numbers,
+/// which is out of scope of the types this tree currently wires up.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Transaction<N: Network> {
+    ciphertexts: Vec<Ciphertext<N>>,
+}
+
+impl<N: Network> Transaction<N> {
+    /// Constructs a coinbase transaction that mints `amount` credits to `recipient`.
+    pub fn new_coinbase<R: Rng + CryptoRng>(
+        recipient: Address<N>,
+        amount: Amount,
+        rng: &mut R,
+    ) -> Result<Self, TransactionError> {
+        if amount.as_microcredits() < 0 {
+            return Err(TransactionError::NegativeAmount(amount));
+        }
+
+        let record = Record::new(recipient, amount);
+        Ok(Self { ciphertexts: vec![Ciphertext::encrypt(record, rng)] })
+    }
+
+    /// Returns this transaction's output record ciphertexts.
+    pub fn ciphertexts(&self) -> &[Ciphertext<N>] {
+        &self.ciphertexts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{account::Account, network::Testnet2};
+    use rand::thread_rng;
+
+    #[test]
+    fn test_new_coinbase_produces_a_decryptable_record_for_the_recipient() {
+        let recipient = Account::<Testnet2>::new(&mut thread_rng());
+        let amount = Amount::from_credits(50);
+
+        let transaction = Transaction::<Testnet2>::new_coinbase(recipient.address(), amount, &mut thread_rng()).unwrap();
+        assert_eq!(transaction.ciphertexts().len(), 1);
+
+        let decrypted = transaction.ciphertexts()[0].decrypt(recipient.view_key()).unwrap();
+        assert_eq!(decrypted.owner(), recipient.address());
+        assert_eq!(decrypted.value(), amount);
+    }
+
+    #[test]
+    fn test_new_coinbase_rejects_a_negative_amount() {
+        let recipient = Account::<Testnet2>::new(&mut thread_rng());
+        let amount = Amount::from_microcredits(-1);
+
+        assert_eq!(
+            Transaction::<Testnet2>::new_coinbase(recipient.address(), amount, &mut thread_rng()),
+            Err(TransactionError::NegativeAmount(amount))
+        );
+    }
+}